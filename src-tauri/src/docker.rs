@@ -0,0 +1,389 @@
+// ── Docker Engine API client ──────────────────────────────────────────────────
+//
+// We talk to the Engine API directly (via `bollard`) instead of shelling out to
+// the `docker` CLI. Shelling out is brittle — it depends on the CLI being on
+// PATH, on English-language output we can grep, and on Windows-only
+// `CREATE_NO_WINDOW` flags — and it can't tell "container missing" apart from
+// "daemon down". Typed API calls give us that distinction directly.
+//
+// This module provides the generic container/volume primitives; the
+// `services` module drives them from the `services.toml` manifest.
+
+use bollard::container::{
+  Config, CreateContainerOptions, RemoveContainerOptions, StartContainerOptions,
+  StopContainerOptions,
+};
+use bollard::errors::Error as BollardError;
+use bollard::models::{HostConfig, PortBinding, RestartPolicy, RestartPolicyNameEnum};
+use bollard::volume::{CreateVolumeOptions, ListVolumesOptions, PruneVolumesOptions, RemoveVolumeOptions};
+use bollard::Docker;
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::services::ServiceSpec;
+use crate::SharedStartupState;
+
+pub const QDRANT_CONTAINER_NAME: &str = "canvaintel-qdrant";
+pub const QDRANT_VOLUME_NAME: &str = "canvaintel_qdrant_data";
+/// Qdrant's default port, used as a fallback when a service spec (or the
+/// default manifest) doesn't resolve one — e.g. no `services.toml` entry.
+pub const QDRANT_DEFAULT_PORT: u16 = 6333;
+/// Name prefix used by every volume this app manages — lets `list`
+/// recognize our own volumes without touching unrelated ones.
+const VOLUME_NAME_PREFIX: &str = "canvaintel_";
+/// Label carried by every volume this app creates, used to scope `prune`.
+const VOLUME_LABEL: &str = "com.canvaintel.managed";
+/// Default host to reach published ports on when the Docker Engine is local.
+pub const LOCAL_HOST: &str = "127.0.0.1";
+const CONNECT_TIMEOUT_SECS: u64 = 4;
+
+/// A resolved connection to a (possibly remote) Docker Engine, plus the host
+/// its published ports should be probed/reached at.
+pub struct DockerConnection {
+  pub client: Docker,
+  pub engine_host: String,
+}
+
+/// Connect to the Docker Engine, honoring `DOCKER_HOST` (tcp/http/unix) when
+/// set, so a remote engine (e.g. a shared host, or Docker-in-WSL2) can be
+/// used instead of assuming a local socket/named pipe. An `ssh://` value is
+/// not supported and returns an error.
+///
+/// `docker_host` is the raw `DOCKER_HOST` value, typically read from
+/// `.env` via [`crate::parse_dotenv`].
+pub fn connect(docker_host: Option<&str>) -> Result<DockerConnection, BollardError> {
+  let Some(host) = docker_host.map(str::trim).filter(|s| !s.is_empty()) else {
+    return Ok(DockerConnection {
+      client: Docker::connect_with_local_defaults()?,
+      engine_host: LOCAL_HOST.to_string(),
+    });
+  };
+
+  if let Some(addr) = host.strip_prefix("tcp://").or_else(|| host.strip_prefix("http://")) {
+    let client = Docker::connect_with_http(host, CONNECT_TIMEOUT_SECS, bollard::API_DEFAULT_VERSION)?;
+    let engine_host = addr.split(':').next().unwrap_or(LOCAL_HOST).to_string();
+    return Ok(DockerConnection { client, engine_host });
+  }
+
+  if let Some(path) = host.strip_prefix("unix://") {
+    let client = Docker::connect_with_unix(path, CONNECT_TIMEOUT_SECS, bollard::API_DEFAULT_VERSION)?;
+    return Ok(DockerConnection { client, engine_host: LOCAL_HOST.to_string() });
+  }
+
+  if host.starts_with("ssh://") {
+    // bollard has no built-in SSH transport (unlike the `docker` CLI, which
+    // multiplexes over an `ssh` subprocess). Silently falling back to the
+    // local engine here would mean every Qdrant/volume op quietly targets
+    // the wrong daemon, so surface it as a connection failure instead.
+    log::error!("[docker] DOCKER_HOST={host:?} uses ssh://, which bollard cannot connect to directly");
+    return Err(BollardError::UnsupportedURISchemeError { uri: host.to_string() });
+  }
+
+  log::warn!(
+    "[docker] DOCKER_HOST={host:?} uses an unsupported scheme (expected tcp://, http://, or unix://) — falling back to local defaults"
+  );
+  Ok(DockerConnection {
+    client: Docker::connect_with_local_defaults()?,
+    engine_host: LOCAL_HOST.to_string(),
+  })
+}
+
+/// Returns `Ok(true)` if `name` exists and is currently running.
+pub(crate) async fn container_is_running(docker: &Docker, name: &str) -> Result<bool, BollardError> {
+  match docker.inspect_container(name, None).await {
+    Ok(info) => Ok(info.state.and_then(|s| s.running).unwrap_or(false)),
+    Err(BollardError::DockerResponseServerError { status_code: 404, .. }) => Ok(false),
+    Err(e) => Err(e),
+  }
+}
+
+/// Returns `Ok(true)` if a container named `name` exists (running or not).
+async fn container_exists(docker: &Docker, name: &str) -> Result<bool, BollardError> {
+  match docker.inspect_container(name, None).await {
+    Ok(_) => Ok(true),
+    Err(BollardError::DockerResponseServerError { status_code: 404, .. }) => Ok(false),
+    Err(e) => Err(e),
+  }
+}
+
+async fn ensure_volume(docker: &Docker, name: &str) -> Result<(), BollardError> {
+  if docker.inspect_volume(name).await.is_ok() {
+    return Ok(());
+  }
+  let mut labels = HashMap::new();
+  labels.insert(VOLUME_LABEL.to_string(), "true".to_string());
+  docker
+    .create_volume(CreateVolumeOptions { name, labels, ..Default::default() })
+    .await?;
+  Ok(())
+}
+
+fn host_config_for(spec: &ServiceSpec) -> HostConfig {
+  let mut port_bindings = HashMap::new();
+  for port in &spec.ports {
+    port_bindings.insert(
+      format!("{}/tcp", port.container),
+      Some(vec![PortBinding {
+        host_ip: Some("0.0.0.0".to_string()),
+        host_port: Some(port.host.to_string()),
+      }]),
+    );
+  }
+
+  let restart_policy_name = match spec.restart_policy.as_str() {
+    "always" => RestartPolicyNameEnum::ALWAYS,
+    "on-failure" => RestartPolicyNameEnum::ON_FAILURE,
+    "no" => RestartPolicyNameEnum::NO,
+    _ => RestartPolicyNameEnum::UNLESS_STOPPED,
+  };
+
+  HostConfig {
+    port_bindings: Some(port_bindings),
+    binds: Some(spec.volumes.clone()),
+    restart_policy: Some(RestartPolicy { name: Some(restart_policy_name), ..Default::default() }),
+    ..Default::default()
+  }
+}
+
+async fn create_and_start_service(docker: &Docker, spec: &ServiceSpec, ss: &SharedStartupState) -> Result<(), BollardError> {
+  ss.lock().unwrap().add_log(&format!("Creating container: {}", spec.name));
+
+  for bind in &spec.volumes {
+    if let Some((volume_name, _)) = bind.split_once(':') {
+      ensure_volume(docker, volume_name).await?;
+    }
+  }
+
+  let env: Vec<String> = spec.env.iter().map(|(k, v)| format!("{k}={v}")).collect();
+
+  docker
+    .create_container(
+      Some(CreateContainerOptions { name: spec.name.as_str(), platform: None }),
+      Config {
+        image: Some(spec.image.as_str()),
+        env: Some(env.iter().map(String::as_str).collect()),
+        host_config: Some(host_config_for(spec)),
+        ..Default::default()
+      },
+    )
+    .await?;
+
+  docker.start_container(&spec.name, None::<StartContainerOptions<String>>).await?;
+
+  ss.lock().unwrap().add_log(&format!("✓ {} created and started", spec.name));
+  Ok(())
+}
+
+/// Make sure `spec`'s container is up, reusing an existing one (stopped or
+/// already running) before creating a fresh one.
+pub async fn ensure_service_running(docker: &Docker, spec: &ServiceSpec, ss: &SharedStartupState) -> Result<(), BollardError> {
+  match container_is_running(docker, &spec.name).await {
+    Ok(true) => {
+      ss.lock().unwrap().add_log(&format!("✓ {} already running", spec.name));
+      return Ok(());
+    }
+    Ok(false) => {}
+    Err(e) => {
+      ss.lock().unwrap().add_log(&format!("⚠ Could not inspect {}: {e}", spec.name));
+    }
+  }
+
+  match container_exists(docker, &spec.name).await {
+    Ok(true) => {
+      // ① Existing, stopped container — just start it.
+      docker.start_container(&spec.name, None::<StartContainerOptions<String>>).await?;
+      ss.lock().unwrap().add_log(&format!("✓ {} started", spec.name));
+      Ok(())
+    }
+    Ok(false) => {
+      // ② Bollard(DockerResponseServerError { status_code: 404 }) → create one.
+      ss.lock().unwrap().add_log(&format!("container not found, creating {}", spec.name));
+      create_and_start_service(docker, spec, ss).await
+    }
+    Err(e) => Err(e),
+  }
+}
+
+/// If `name`'s container defines a Docker `HEALTHCHECK`, poll
+/// `inspect_container` until it reports `healthy` (or `attempts` run out).
+/// Returns `None` when no health check is configured, so the caller should
+/// fall back to polling the readiness HTTP endpoints itself.
+pub fn wait_for_container_healthy(
+  docker_host: Option<&str>,
+  name: &str,
+  attempts: u32,
+  delay_ms: u64,
+  ss: &SharedStartupState,
+) -> Option<bool> {
+  let docker = connect(docker_host).ok()?.client;
+
+  tauri::async_runtime::block_on(async {
+    let initial = docker.inspect_container(name, None).await.ok()?;
+    initial.state.as_ref()?.health.as_ref()?;
+
+    for _ in 0..attempts {
+      match docker.inspect_container(name, None).await {
+        Ok(info) => match info.state.and_then(|s| s.health).and_then(|h| h.status) {
+          Some(bollard::models::HealthStatusEnum::HEALTHY) => return Some(true),
+          Some(other) => {
+            ss.lock().unwrap().add_log(&format!("{name} health status: {other:?} — retrying"));
+          }
+          None => {}
+        },
+        Err(e) => {
+          ss.lock().unwrap().add_log(&format!("⚠ Could not inspect {name} health: {e}"));
+        }
+      }
+      std::thread::sleep(std::time::Duration::from_millis(delay_ms));
+    }
+    Some(false)
+  })
+}
+
+/// Stop a service's container if it's running. Best-effort — errors are
+/// logged, never propagated, since this runs during shutdown.
+pub async fn stop_service(docker: &Docker, name: &str) {
+  match docker.stop_container(name, None::<StopContainerOptions>).await {
+    Ok(()) => log::info!("Stopped service: {name}"),
+    Err(BollardError::DockerResponseServerError { status_code: 404, .. }) => {
+      log::info!("Container {name} not found — nothing to stop");
+    }
+    Err(e) => log::warn!("Failed to stop {name}: {e}"),
+  }
+}
+
+#[allow(dead_code)]
+async fn remove_container(docker: &Docker, name: &str) -> Result<(), BollardError> {
+  docker
+    .remove_container(name, Some(RemoveContainerOptions { force: true, ..Default::default() }))
+    .await
+}
+
+// ── Volume lifecycle ─────────────────────────────────────────────────────────
+//
+// Backs the `list_canvas_volumes` / `create_qdrant_volume` / `remove_qdrant_volume`
+// / `prune_unused_volumes` Tauri commands.
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VolumeInfo {
+  pub name: String,
+  pub driver: String,
+  pub mountpoint: String,
+  pub size_bytes: Option<i64>,
+}
+
+/// Typed error the frontend can match on, instead of an opaque string.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", content = "detail")]
+pub enum VolumeError {
+  /// Refused to remove a volume while its container is still running.
+  ContainerRunning(String),
+  /// Any other Docker Engine API failure.
+  Docker(String),
+}
+
+impl std::fmt::Display for VolumeError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      VolumeError::ContainerRunning(msg) | VolumeError::Docker(msg) => write!(f, "{msg}"),
+    }
+  }
+}
+
+impl std::error::Error for VolumeError {}
+
+impl From<BollardError> for VolumeError {
+  fn from(e: BollardError) -> Self {
+    VolumeError::Docker(e.to_string())
+  }
+}
+
+fn to_volume_info(v: bollard::models::Volume) -> VolumeInfo {
+  VolumeInfo {
+    name: v.name,
+    driver: v.driver,
+    mountpoint: v.mountpoint,
+    size_bytes: v.usage_data.and_then(|u| if u.size >= 0 { Some(u.size) } else { None }),
+  }
+}
+
+/// `GET /volumes` never populates `UsageData` (the Engine API only computes
+/// disk usage for `/system/df`), so sizes are fetched separately from there
+/// and merged in by name.
+async fn volume_sizes(docker: &Docker) -> HashMap<String, i64> {
+  match docker.df().await {
+    Ok(usage) => usage
+      .volumes
+      .unwrap_or_default()
+      .into_iter()
+      .filter_map(|v| v.usage_data.map(|u| (v.name, u.size)))
+      .filter(|(_, size)| *size >= 0)
+      .collect(),
+    Err(e) => {
+      log::warn!("[docker] Could not fetch volume sizes via /system/df: {e}");
+      HashMap::new()
+    }
+  }
+}
+
+/// List every volume this app owns (name prefixed with `canvaintel_`), with
+/// driver, mountpoint, and size.
+pub async fn list_volumes(docker_host: Option<&str>) -> Result<Vec<VolumeInfo>, VolumeError> {
+  let conn = connect(docker_host)?;
+
+  let mut filters = HashMap::new();
+  filters.insert("name".to_string(), vec![VOLUME_NAME_PREFIX.to_string()]);
+
+  let resp = conn.client.list_volumes(Some(ListVolumesOptions { filters })).await?;
+  let sizes = volume_sizes(&conn.client).await;
+
+  Ok(
+    resp
+      .volumes
+      .unwrap_or_default()
+      .into_iter()
+      .filter(|v| v.name.starts_with(VOLUME_NAME_PREFIX))
+      .map(|v| {
+        let mut info = to_volume_info(v);
+        info.size_bytes = info.size_bytes.or_else(|| sizes.get(&info.name).copied());
+        info
+      })
+      .collect(),
+  )
+}
+
+/// Create the `canvaintel_qdrant_data` volume if it doesn't already exist.
+pub async fn create_qdrant_volume(docker_host: Option<&str>) -> Result<VolumeInfo, VolumeError> {
+  let conn = connect(docker_host)?;
+  ensure_volume(&conn.client, QDRANT_VOLUME_NAME).await?;
+  let v = conn.client.inspect_volume(QDRANT_VOLUME_NAME).await?;
+  Ok(to_volume_info(v))
+}
+
+/// Remove a volume by name. Refuses while the Qdrant container is running,
+/// since the Engine API would otherwise fail with a less actionable error
+/// (or, for a stopped-but-still-attached container, silently orphan it).
+pub async fn remove_volume(docker_host: Option<&str>, name: &str) -> Result<(), VolumeError> {
+  let conn = connect(docker_host)?;
+
+  if name == QDRANT_VOLUME_NAME && container_is_running(&conn.client, QDRANT_CONTAINER_NAME).await.unwrap_or(false) {
+    return Err(VolumeError::ContainerRunning(format!(
+      "Qdrant container {QDRANT_CONTAINER_NAME} is still running — stop it before removing {name}"
+    )));
+  }
+
+  conn.client.remove_volume(name, None::<RemoveVolumeOptions>).await?;
+  Ok(())
+}
+
+/// Prune unused volumes, scoped to ones carrying this app's own label so it
+/// never touches volumes created by other tools or the user.
+pub async fn prune_unused_volumes(docker_host: Option<&str>) -> Result<Vec<String>, VolumeError> {
+  let conn = connect(docker_host)?;
+
+  let mut filters = HashMap::new();
+  filters.insert("label".to_string(), vec![format!("{VOLUME_LABEL}=true")]);
+
+  let resp = conn.client.prune_volumes(Some(PruneVolumesOptions { filters })).await?;
+  Ok(resp.volumes_deleted.unwrap_or_default())
+}
@@ -13,6 +13,10 @@ use std::time::Duration;
 use tauri::path::BaseDirectory;
 use tauri::{AppHandle, Manager, RunEvent, State};
 
+mod docker;
+mod services;
+mod shutdown;
+
 #[cfg(windows)]
 const CREATE_NO_WINDOW: u32 = 0x08000000;
 
@@ -37,13 +41,22 @@ pub struct StartupStatus {
   pub message: String,
   pub elapsed_ms: u64,
   pub logs: Vec<String>,
+  pub backend_restart_count: u32,
+  pub backend_last_exit_code: Option<i32>,
 }
 
-struct StartupState {
+pub(crate) struct StartupState {
   phase: String,
   message: String,
   started_at: std::time::Instant,
   logs: Vec<String>,
+  /// Raw `DOCKER_HOST` value resolved from `.env` at startup, if any — kept
+  /// around so shutdown can reconnect to the same (possibly remote) engine.
+  docker_host_env: Option<String>,
+  /// How many times the supervisor has restarted a crashed backend.
+  backend_restart_count: u32,
+  /// Exit code of the backend's most recent unexpected exit, if any.
+  backend_last_exit_code: Option<i32>,
 }
 
 impl StartupState {
@@ -53,10 +66,21 @@ impl StartupState {
       message: "Starting up...".to_string(),
       started_at: std::time::Instant::now(),
       logs: Vec::new(),
+      docker_host_env: None,
+      backend_restart_count: 0,
+      backend_last_exit_code: None,
     }
   }
 
-  fn set_phase(&mut self, phase: &str, message: &str) {
+  pub(crate) fn set_docker_host_env(&mut self, host: Option<String>) {
+    self.docker_host_env = host;
+  }
+
+  pub(crate) fn docker_host_env(&self) -> Option<String> {
+    self.docker_host_env.clone()
+  }
+
+  pub(crate) fn set_phase(&mut self, phase: &str, message: &str) {
     self.phase = phase.to_string();
     self.message = message.to_string();
     let entry = format!("[{:.1}s] {}", self.started_at.elapsed().as_secs_f32(), message);
@@ -64,23 +88,32 @@ impl StartupState {
     self.logs.push(entry);
   }
 
-  fn add_log(&mut self, msg: &str) {
+  pub(crate) fn add_log(&mut self, msg: &str) {
     let entry = format!("[{:.1}s] {}", self.started_at.elapsed().as_secs_f32(), msg);
     log::info!("[startup] {msg}");
     self.logs.push(entry);
   }
 
+  /// Record an unexpected backend exit. Returns the new restart count.
+  pub(crate) fn record_backend_crash(&mut self, exit_code: Option<i32>) -> u32 {
+    self.backend_restart_count += 1;
+    self.backend_last_exit_code = exit_code;
+    self.backend_restart_count
+  }
+
   fn to_status(&self) -> StartupStatus {
     StartupStatus {
       phase: self.phase.clone(),
       message: self.message.clone(),
       elapsed_ms: self.started_at.elapsed().as_millis() as u64,
       logs: self.logs.clone(),
+      backend_restart_count: self.backend_restart_count,
+      backend_last_exit_code: self.backend_last_exit_code,
     }
   }
 }
 
-type SharedStartupState = Arc<Mutex<StartupState>>;
+pub(crate) type SharedStartupState = Arc<Mutex<StartupState>>;
 
 /// Tauri command — polled by the frontend loading screen every ~400 ms.
 #[tauri::command]
@@ -88,10 +121,100 @@ fn get_startup_status(state: State<SharedStartupState>) -> StartupStatus {
   state.lock().unwrap_or_else(|e| e.into_inner()).to_status()
 }
 
+// ── Volume maintenance commands ──────────────────────────────────────────────
+
+#[tauri::command]
+async fn list_canvas_volumes(state: State<'_, SharedStartupState>) -> Result<Vec<docker::VolumeInfo>, docker::VolumeError> {
+  let docker_host = state.lock().unwrap_or_else(|e| e.into_inner()).docker_host_env();
+  docker::list_volumes(docker_host.as_deref()).await
+}
+
+#[tauri::command]
+async fn create_qdrant_volume(state: State<'_, SharedStartupState>) -> Result<docker::VolumeInfo, docker::VolumeError> {
+  let docker_host = state.lock().unwrap_or_else(|e| e.into_inner()).docker_host_env();
+  docker::create_qdrant_volume(docker_host.as_deref()).await
+}
+
+#[tauri::command]
+async fn remove_qdrant_volume(state: State<'_, SharedStartupState>, name: String) -> Result<(), docker::VolumeError> {
+  let docker_host = state.lock().unwrap_or_else(|e| e.into_inner()).docker_host_env();
+  docker::remove_volume(docker_host.as_deref(), &name).await
+}
+
+#[tauri::command]
+async fn prune_unused_volumes(state: State<'_, SharedStartupState>) -> Result<Vec<String>, docker::VolumeError> {
+  let docker_host = state.lock().unwrap_or_else(|e| e.into_inner()).docker_host_env();
+  docker::prune_unused_volumes(docker_host.as_deref()).await
+}
+
 // ── .env parser (so we never bake secrets at compile time) ──────────────────
 
+/// Strip exactly one matching pair of outer quotes (if present), leaving
+/// inner whitespace untouched. Returns the unquoted value and whether it was
+/// single-quoted — single-quoted dotenv values are literal and skip `$`
+/// interpolation, matching the usual shell/dotenv convention.
+fn strip_outer_quotes(raw: &str) -> (&str, bool) {
+  let bytes = raw.as_bytes();
+  if bytes.len() >= 2 {
+    let (first, last) = (bytes[0], bytes[bytes.len() - 1]);
+    if first == last && (first == b'"' || first == b'\'') {
+      return (&raw[1..raw.len() - 1], first == b'\'');
+    }
+  }
+  (raw, false)
+}
+
+/// Expand `${VAR}`/`$VAR` references in `value` against `vars` (entries
+/// parsed earlier in the same file take precedence) and, failing that, the
+/// process environment. Unresolved references expand to an empty string.
+/// Returns the expanded value and how many references were resolved.
+fn interpolate(value: &str, vars: &HashMap<String, String>) -> (String, usize) {
+  let mut out = String::with_capacity(value.len());
+  let mut resolved = 0;
+  let chars: Vec<char> = value.chars().collect();
+  let mut i = 0;
+  while i < chars.len() {
+    if chars[i] != '$' || i + 1 >= chars.len() {
+      out.push(chars[i]);
+      i += 1;
+      continue;
+    }
+    let (name, consumed) = if chars[i + 1] == '{' {
+      match chars[i + 2..].iter().position(|&c| c == '}') {
+        Some(end) => {
+          let name: String = chars[i + 2..i + 2 + end].iter().collect();
+          (name, 2 + end + 1)
+        }
+        None => {
+          // Unterminated `${` — treat the `$` as a literal character.
+          out.push('$');
+          i += 1;
+          continue;
+        }
+      }
+    } else {
+      let end = chars[i + 1..].iter().position(|c| !(c.is_alphanumeric() || *c == '_')).map(|p| i + 1 + p).unwrap_or(chars.len());
+      if end == i + 1 {
+        // `$` not followed by a valid identifier character — literal.
+        out.push('$');
+        i += 1;
+        continue;
+      }
+      (chars[i + 1..end].iter().collect(), end - i)
+    };
+
+    if let Some(v) = vars.get(&name).cloned().or_else(|| std::env::var(&name).ok()) {
+      out.push_str(&v);
+      resolved += 1;
+    }
+    i += consumed;
+  }
+  (out, resolved)
+}
+
 fn parse_dotenv(cwd: &PathBuf) -> HashMap<String, String> {
   let mut vars = HashMap::new();
+  let mut interpolations = 0;
   let env_path = cwd.join(".env");
   match fs::read_to_string(&env_path) {
     Ok(content) => {
@@ -101,18 +224,26 @@ fn parse_dotenv(cwd: &PathBuf) -> HashMap<String, String> {
           continue;
         }
         if let Some(pos) = line.find('=') {
-          let key = line[..pos].trim().to_string();
-          let val = line[pos + 1..]
-            .trim()
-            .trim_matches('"')
-            .trim_matches('\'')
-            .to_string();
+          let key = line[..pos].trim().trim_start_matches("export ").trim().to_string();
+          let raw_val = line[pos + 1..].trim();
+          let (unquoted, literal) = strip_outer_quotes(raw_val);
+          let val = if literal {
+            unquoted.to_string()
+          } else {
+            let (expanded, count) = interpolate(unquoted, &vars);
+            interpolations += count;
+            expanded
+          };
           if !key.is_empty() {
             vars.insert(key, val);
           }
         }
       }
-      log::info!("[startup] Loaded {} vars from {:?}", vars.len(), env_path);
+      log::info!(
+        "[startup] Loaded {} vars from {:?} ({interpolations} interpolation(s) resolved)",
+        vars.len(),
+        env_path
+      );
     }
     Err(e) => {
       log::warn!("[startup] Could not read {:?}: {e}", env_path);
@@ -123,7 +254,7 @@ fn parse_dotenv(cwd: &PathBuf) -> HashMap<String, String> {
 
 // ── Project root / backend path resolution ───────────────────────────────────
 
-fn find_project_root() -> Option<PathBuf> {
+pub(crate) fn find_project_root() -> Option<PathBuf> {
   let compile_time_root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
     .parent()
     .map(|p| p.to_path_buf());
@@ -169,122 +300,66 @@ fn resolve_backend_paths(app: &AppHandle) -> Option<(PathBuf, PathBuf)> {
   candidates.into_iter().find(|(entry, cwd)| entry.exists() && cwd.exists())
 }
 
-// ── Qdrant ───────────────────────────────────────────────────────────────────
-
-fn ensure_qdrant_running(ss: &SharedStartupState) {
-  const NAME: &str = "canvaintel-qdrant";
-
-  ss.lock().unwrap().set_phase("qdrant", "Starting Qdrant vector database...");
-
-  // If anything is already serving Qdrant on localhost:6333, reuse it.
-  if TcpStream::connect(("127.0.0.1", 6333)).is_ok() {
-    ss.lock().unwrap().add_log("✓ Qdrant already reachable on 127.0.0.1:6333 (reusing existing instance)");
-    return;
-  }
-
-  // ① Try to start an existing stopped container
-  let mut start_cmd = Command::new("docker");
-  start_cmd.args(["start", NAME]);
-  match apply_no_window(&mut start_cmd).output() {
-    Ok(out) if out.status.success() => {
-      log_docker_output(&out.stdout, &out.stderr, ss);
-      ss.lock().unwrap().add_log(&format!("✓ Qdrant container started: {NAME}"));
-      return;
-    }
-    Ok(out) => {
-      log_docker_output(&out.stdout, &out.stderr, ss);
-      // Container not found → fall through to create
-    }
-    Err(e) => {
-      ss.lock().unwrap()
-        .add_log(&format!("⚠ docker start failed: {e} — ensure Docker Desktop is running"));
-      return;
-    }
-  }
+// ── Readiness probes ──────────────────────────────────────────────────────────
+//
+// Shared by the `docker`/`services` modules, which drive the actual
+// container lifecycle through the Docker Engine API.
 
-  // ② Create and start a fresh container
-  ss.lock().unwrap().add_log(&format!("Creating Qdrant container: {NAME}"));
-  let mut run_cmd = Command::new("docker");
-  run_cmd.args([
-    "run", "-d",
-    "--name", NAME,
-    "-p", "6333:6333",
-    "-v", "canvaintel_qdrant_data:/qdrant/storage",
-    "--restart", "unless-stopped",
-    "qdrant/qdrant:latest",
-  ]);
-  match apply_no_window(&mut run_cmd).output() {
-    Ok(out) => {
-      log_docker_output(&out.stdout, &out.stderr, ss);
-      if out.status.success() {
-        ss.lock().unwrap().add_log("✓ Qdrant container created and started");
-      } else {
-        ss.lock().unwrap()
-          .add_log("⚠ Failed to create Qdrant container — ensure Docker Desktop is running");
-      }
-    }
-    Err(e) => {
-      ss.lock().unwrap()
-        .add_log(&format!("⚠ docker run failed: {e}"));
+pub(crate) fn wait_for_port(host: &str, port: u16, attempts: u32, delay_ms: u64) -> bool {
+  for _ in 0..attempts {
+    if TcpStream::connect((host, port)).is_ok() {
+      return true;
     }
+    std::thread::sleep(Duration::from_millis(delay_ms));
   }
+  false
 }
 
-fn log_docker_output(stdout: &[u8], stderr: &[u8], ss: &SharedStartupState) {
-  let mut guard = ss.lock().unwrap();
-  for line in String::from_utf8_lossy(stdout).lines() {
-    let l = line.trim();
-    if !l.is_empty() {
-      guard.add_log(&format!("  docker › {l}"));
-    }
-  }
-  for line in String::from_utf8_lossy(stderr).lines() {
-    let l = line.trim();
-    if !l.is_empty() {
-      guard.add_log(&format!("  docker › {l}"));
-    }
-  }
+/// Issue a minimal HTTP/1.1 GET over a raw socket and return the response
+/// status code, if the request completed at all.
+fn http_get_status(host: &str, port: u16, path: &str, timeout: Duration) -> Option<u16> {
+  use std::io::{Read, Write};
+  let mut stream = TcpStream::connect((host, port)).ok()?;
+  stream.set_read_timeout(Some(timeout)).ok();
+  stream.set_write_timeout(Some(timeout)).ok();
+  let request = format!("GET {path} HTTP/1.1\r\nHost: {host}\r\nConnection: close\r\n\r\n");
+  stream.write_all(request.as_bytes()).ok()?;
+  let mut buf = Vec::new();
+  stream.read_to_end(&mut buf).ok()?;
+  let response = String::from_utf8_lossy(&buf);
+  response.lines().next()?.split_whitespace().nth(1)?.parse().ok()
 }
 
-fn wait_for_port(port: u16, attempts: u32, delay_ms: u64) -> bool {
+/// Poll `paths` over HTTP (in order) until one returns 200, or until
+/// `attempts` run out. Unlike `wait_for_port`, this distinguishes "socket
+/// accepted but app not ready" (e.g. Qdrant serving before collections load)
+/// from a true timeout, and logs each unready attempt so the loading screen
+/// can show it.
+pub(crate) fn wait_for_health(
+  host: &str,
+  port: u16,
+  paths: &[&str],
+  attempts: u32,
+  delay_ms: u64,
+  ss: &SharedStartupState,
+  label: &str,
+) -> bool {
   for _ in 0..attempts {
-    if TcpStream::connect(("127.0.0.1", port)).is_ok() {
-      return true;
+    if TcpStream::connect((host, port)).is_ok() {
+      for path in paths {
+        match http_get_status(host, port, path, Duration::from_millis(1500)) {
+          Some(200) => return true,
+          Some(code) => ss.lock().unwrap()
+            .add_log(&format!("{label} accepting TCP but not ready ({code}) on {path} — retrying")),
+          None => {}
+        }
+      }
     }
     std::thread::sleep(Duration::from_millis(delay_ms));
   }
   false
 }
 
-fn stop_qdrant_container() {
-  // Stop any running container currently publishing host port 6333.
-  let mut by_port_cmd = Command::new("docker");
-  by_port_cmd.args(["ps", "--filter", "publish=6333", "--format", "{{.Names}}"]);
-  let by_port = apply_no_window(&mut by_port_cmd).output();
-
-  if let Ok(out) = by_port {
-    let names = String::from_utf8_lossy(&out.stdout);
-    let mut stopped_any = false;
-    for name in names.lines().map(str::trim).filter(|n| !n.is_empty()) {
-      let mut stop_cmd = Command::new("docker");
-      stop_cmd.args(["stop", name]);
-      let _ = apply_no_window(&mut stop_cmd).output();
-      log::info!("Stopped Qdrant container: {name}");
-      stopped_any = true;
-    }
-    if stopped_any {
-      return;
-    }
-  }
-
-  // Fallback to the legacy managed name if port-based detection found nothing.
-  const NAME: &str = "canvaintel-qdrant";
-  let mut stop_cmd = Command::new("docker");
-  stop_cmd.args(["stop", NAME]);
-  let _ = apply_no_window(&mut stop_cmd).output();
-  log::info!("Attempted to stop Qdrant container: {NAME}");
-}
-
 // ── node_modules extraction ───────────────────────────────────────────────────
 
 fn ensure_node_modules_unpacked(app: &AppHandle, ss: &SharedStartupState) {
@@ -381,7 +456,7 @@ fn ensure_node_modules_unpacked(app: &AppHandle, ss: &SharedStartupState) {
 
 // ── Backend start / stop ─────────────────────────────────────────────────────
 
-fn start_backend(app: &AppHandle, ss: &SharedStartupState) {
+fn start_backend(app: &AppHandle, ss: &SharedStartupState, env_vars: &HashMap<String, String>, qdrant_host: &str, qdrant_port: u16) {
   ss.lock().unwrap().set_phase("backend_starting", "Starting Node.js backend server...");
 
   let Some((entry, cwd)) = resolve_backend_paths(app) else {
@@ -390,12 +465,6 @@ fn start_backend(app: &AppHandle, ss: &SharedStartupState) {
     return;
   };
 
-  // ── Load API keys / config from backend/.env ─────────────────────────────
-  // NOTE: We explicitly parse .env here rather than letting the Node process
-  // dotenv load it because we previously passed GROQ_API_KEY="" which silently
-  // overrode whatever was in the file.
-  let env_vars = parse_dotenv(&cwd);
-
   // Storage root priority:
   // 1) BACKEND_STORAGE_ROOT from .env (explicit override)
   // 2) In local dev (backend/package.json exists), use project-root /storage
@@ -479,13 +548,13 @@ fn start_backend(app: &AppHandle, ss: &SharedStartupState) {
     // These are the safe defaults — overridden by .env below if present
     .env("NODE_ENV",               "production")
     .env("BACKEND_PORT",           "3001")
-    .env("QDRANT_URL",             "http://127.0.0.1:6333")
+    .env("QDRANT_URL",             format!("http://{qdrant_host}:{qdrant_port}"))
     .env("BACKEND_STORAGE_ROOT",   &storage_root)
     .env("OLLAMA_BASE_URL",        "http://localhost:11434")
     .env("OLLAMA_CHAT_MODEL",      "minimax-m2.5:cloud");
 
   // Apply all .env values (including GROQ_API_KEY, overrides defaults above)
-  for (k, v) in &env_vars {
+  for (k, v) in env_vars {
     cmd.env(k, v);
   }
 
@@ -503,11 +572,22 @@ fn start_backend(app: &AppHandle, ss: &SharedStartupState) {
   );
 
   match apply_no_window(&mut cmd).spawn() {
-    Ok(child) => {
+    Ok(mut child) => {
       if let Ok(mut guard) = app.state::<BackendProcess>().0.lock() {
-        *guard = Some(child);
+        // `stop_backend` takes this same lock to reap the child on shutdown.
+        // If shutdown was signaled between the restart-backoff sleep and
+        // here, `stop_backend` may already have run (and found nothing to
+        // kill) — recheck under the lock so the two can't race into leaving
+        // this freshly spawned child unreaped.
+        if shutdown::is_shutting_down() {
+          let _ = child.kill();
+          let _ = child.wait();
+          ss.lock().unwrap().add_log("Shutdown in progress — killed backend spawned just after teardown");
+        } else {
+          *guard = Some(child);
+          ss.lock().unwrap().add_log("✓ Backend process spawned");
+        }
       }
-      ss.lock().unwrap().add_log("✓ Backend process spawned");
     }
     Err(e) => {
       ss.lock().unwrap().add_log(&format!("⚠ Failed to spawn backend: {e}"));
@@ -515,7 +595,102 @@ fn start_backend(app: &AppHandle, ss: &SharedStartupState) {
   }
 }
 
-fn stop_backend(app: &AppHandle) {
+const BACKEND_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+const BACKEND_RESTART_BASE_DELAY_MS: u64 = 1000;
+const BACKEND_RESTART_MAX_DELAY_MS: u64 = 30_000;
+const BACKEND_MAX_RESTARTS: u32 = 5;
+/// How long the backend has to stay up after a restart before we stop
+/// counting that crash against the `BACKEND_MAX_RESTARTS` ceiling — guards
+/// against a rapid crash loop, not against a backend that's merely had a
+/// few unrelated crashes over a long, otherwise-stable lifetime.
+const BACKEND_STABILITY_WINDOW: Duration = Duration::from_secs(BACKEND_WATCH_INTERVAL.as_secs() * 15);
+
+/// Watch the backend child for an unexpected exit and relaunch it with
+/// exponential backoff (1s, 2s, 4s, ... capped at 30s). Gives up on the
+/// crash that follows `BACKEND_MAX_RESTARTS` *consecutive* restarts — the streak (and the
+/// backoff delay) resets once the backend has stayed up for
+/// `BACKEND_STABILITY_WINDOW`, so a backend that crashes once, recovers, and
+/// then runs for hours isn't one crash away from `backend_failed`. Exits
+/// quietly once `stop_backend` has taken the child (i.e. the app is shutting
+/// down), and also bails out without relaunching if shutdown starts during
+/// the backoff sleep — otherwise a crash that lands right before
+/// `shutdown::teardown` runs would have this spawn a fresh, unreaped backend
+/// after teardown already declared the app done.
+fn supervise_backend(app: AppHandle, ss: SharedStartupState, env_vars: HashMap<String, String>, qdrant_host: String, qdrant_port: u16) {
+  let mut delay_ms = BACKEND_RESTART_BASE_DELAY_MS;
+  let mut consecutive_crashes: u32 = 0;
+  let mut last_restart_at = std::time::Instant::now();
+
+  loop {
+    std::thread::sleep(BACKEND_WATCH_INTERVAL);
+
+    let exit_status = {
+      let state = app.state::<BackendProcess>();
+      let mut guard = match state.0.lock() {
+        Ok(g) => g,
+        Err(_) => return,
+      };
+      match guard.as_mut() {
+        None => return, // stopped intentionally, or never started
+        Some(child) => match child.try_wait() {
+          Ok(Some(status)) => {
+            *guard = None;
+            Some(status)
+          }
+          Ok(None) => None,
+          Err(e) => {
+            log::warn!("Failed to poll backend process: {e}");
+            None
+          }
+        },
+      }
+    };
+
+    let Some(status) = exit_status else { continue };
+
+    if shutdown::is_shutting_down() {
+      return; // Exited as part of a normal shutdown — nothing to restart.
+    }
+
+    let exit_code = status.code();
+    let lifetime_restart_count = ss.lock().unwrap().record_backend_crash(exit_code);
+
+    if last_restart_at.elapsed() >= BACKEND_STABILITY_WINDOW {
+      // It stayed up a good while before this crash — don't let an old,
+      // already-recovered-from crash streak count against it.
+      consecutive_crashes = 0;
+      delay_ms = BACKEND_RESTART_BASE_DELAY_MS;
+    }
+    consecutive_crashes += 1;
+
+    if consecutive_crashes > BACKEND_MAX_RESTARTS {
+      ss.lock().unwrap().set_phase(
+        "backend_failed",
+        &format!("⚠ Backend crashed {consecutive_crashes} times in a row (last exit {exit_code:?}) — giving up"),
+      );
+      return;
+    }
+
+    let lifetime_restart_count = ss.lock().unwrap().record_backend_crash(exit_code);
+    ss.lock().unwrap().set_phase(
+      "backend_crashed",
+      &format!(
+        "Backend exited (code {exit_code:?}) — restarting in {delay_ms}ms (attempt {consecutive_crashes}, {lifetime_restart_count} lifetime)"
+      ),
+    );
+    std::thread::sleep(Duration::from_millis(delay_ms));
+    delay_ms = (delay_ms * 2).min(BACKEND_RESTART_MAX_DELAY_MS);
+
+    if shutdown::is_shutting_down() {
+      return; // Shutdown started during the backoff sleep — don't resurrect it.
+    }
+
+    start_backend(&app, &ss, &env_vars, &qdrant_host, qdrant_port);
+    last_restart_at = std::time::Instant::now();
+  }
+}
+
+pub(crate) fn stop_backend(app: &AppHandle) {
   let state = app.state::<BackendProcess>();
   let mut guard = match state.0.lock() {
     Ok(g) => g,
@@ -537,7 +712,13 @@ pub fn run() {
   let app = tauri::Builder::default()
     .manage(BackendProcess::default())
     .manage(startup_state.clone())
-    .invoke_handler(tauri::generate_handler![get_startup_status])
+    .invoke_handler(tauri::generate_handler![
+      get_startup_status,
+      list_canvas_volumes,
+      create_qdrant_volume,
+      remove_qdrant_volume,
+      prune_unused_volumes,
+    ])
     .setup(move |app| {
       if cfg!(debug_assertions) {
         app.handle().plugin(
@@ -550,28 +731,58 @@ pub fn run() {
       let handle = app.handle().clone();
       let ss = startup_state.clone();
 
-      std::thread::spawn(move || {
-        // 1. Start Qdrant (captures docker output)
-        ensure_qdrant_running(&ss);
+      // Catch SIGTERM/SIGINT (or, on Windows, a console-close/shutdown
+      // event) so a managed shutdown — not just quitting the window — tears
+      // the backend and Docker services down too.
+      shutdown::install(handle.clone());
 
-        // 2. Wait for Qdrant port
-        ss.lock().unwrap().set_phase("qdrant_wait", "Waiting for Qdrant on port 6333...");
-        if wait_for_port(6333, 20, 500) {
-          ss.lock().unwrap().add_log("✓ Qdrant is ready");
-        } else {
-          ss.lock().unwrap()
-            .add_log("⚠ Qdrant not ready after 10s — vector search may be unavailable");
-        }
-
-        // 3. Unpack node_modules.zip (first launch only, PowerShell-fast)
+      std::thread::spawn(move || {
+        // 0. Load .env once up front — DOCKER_HOST (for a remote engine) and
+        //    QDRANT_URL/GROQ_API_KEY overrides all come from here.
+        // NOTE: We explicitly parse .env here rather than letting the Node
+        // process dotenv load it because we previously passed
+        // GROQ_API_KEY="" which silently overrode whatever was in the file.
+        let env_vars = resolve_backend_paths(&handle)
+          .map(|(_, cwd)| parse_dotenv(&cwd))
+          .unwrap_or_default();
+
+        // 1. Bring up every managed service (Qdrant, and any sidecars added
+        //    to services.toml — e.g. Ollama) in manifest order, honoring
+        //    DOCKER_HOST for a remote/WSL2 engine. Each service's readiness
+        //    (container health check, falling back to its HTTP paths) feeds
+        //    the startup phases as it comes up.
+        let docker_host = env_vars.get("DOCKER_HOST").cloned();
+        ss.lock().unwrap().set_docker_host_env(docker_host.clone());
+        let manifest = services::load_manifest(&handle, find_project_root().as_ref());
+        let service_hosts = services::bring_up(docker_host.as_deref(), &manifest, &ss);
+        let qdrant_endpoint = service_hosts.get(docker::QDRANT_CONTAINER_NAME);
+        let qdrant_host = qdrant_endpoint.map(|e| e.host.clone()).unwrap_or_else(|| docker::LOCAL_HOST.to_string());
+        let qdrant_port = qdrant_endpoint.and_then(|e| e.port).unwrap_or(docker::QDRANT_DEFAULT_PORT);
+
+        // 2. Unpack node_modules.zip (first launch only, PowerShell-fast)
         ensure_node_modules_unpacked(&handle, &ss);
 
-        // 4. Start Node.js backend (reads .env properly)
-        start_backend(&handle, &ss);
-
-        // 5. Wait for backend HTTP server
+        // 3. Start Node.js backend (reusing the .env already parsed above)
+        start_backend(&handle, &ss, &env_vars, &qdrant_host, qdrant_port);
+
+        // 3b. Supervise it — relaunch with backoff on an unexpected exit.
+        std::thread::spawn({
+          let handle = handle.clone();
+          let ss = ss.clone();
+          let env_vars = env_vars.clone();
+          let qdrant_host = qdrant_host.clone();
+          move || supervise_backend(handle, ss, env_vars, qdrant_host, qdrant_port)
+        });
+
+        // 4. Wait for the backend's HTTP health endpoint (configurable via
+        //    BACKEND_HEALTH_PATH, defaulting to /health) rather than just its
+        //    port accepting connections.
+        let backend_health_path = env_vars
+          .get("BACKEND_HEALTH_PATH")
+          .cloned()
+          .unwrap_or_else(|| "/health".to_string());
         ss.lock().unwrap().set_phase("backend_wait", "Waiting for backend on port 3001...");
-        if wait_for_port(3001, 60, 500) {
+        if wait_for_health("127.0.0.1", 3001, &[backend_health_path.as_str()], 60, 500, &ss, "Backend") {
           ss.lock().unwrap().set_phase("ready", "✓ Backend is ready!");
         } else {
           ss.lock().unwrap()
@@ -586,8 +797,7 @@ pub fn run() {
 
   app.run(|app, event| {
     if matches!(event, RunEvent::Exit | RunEvent::ExitRequested { .. }) {
-      stop_backend(app);
-      stop_qdrant_container();
+      shutdown::teardown(app);
     }
   });
 }
@@ -0,0 +1,190 @@
+// ── Compose-style service manifest ────────────────────────────────────────────
+//
+// Qdrant startup used to be hard-coded, with no story for other co-services
+// (the backend already expects Ollama at `OLLAMA_BASE_URL`, with nothing
+// managing that dependency). `services.toml` describes the set of
+// Docker-backed services the app depends on; `bring_up`/`bring_down` drive
+// the whole dependency graph from it instead of a single Qdrant-only call.
+// Adding another sidecar is a manifest edit, not new Rust.
+
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::net::TcpStream;
+use std::path::PathBuf;
+
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Manager};
+
+use crate::docker::{self, DockerConnection};
+use crate::SharedStartupState;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PortSpec {
+  pub host: u16,
+  pub container: u16,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ReadinessSpec {
+  /// HTTP paths to poll in order (first 200 wins). Empty means "TCP only".
+  #[serde(default)]
+  pub http_paths: Vec<String>,
+}
+
+fn default_restart_policy() -> String {
+  "unless-stopped".to_string()
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServiceSpec {
+  pub name: String,
+  pub image: String,
+  #[serde(default)]
+  pub ports: Vec<PortSpec>,
+  /// `"volume_name:/container/path"` bind strings, same shape the Engine API
+  /// expects in `HostConfig::binds`.
+  #[serde(default)]
+  pub volumes: Vec<String>,
+  #[serde(default)]
+  pub env: HashMap<String, String>,
+  #[serde(default = "default_restart_policy")]
+  pub restart_policy: String,
+  pub readiness: Option<ReadinessSpec>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct Manifest {
+  #[serde(default)]
+  pub service: Vec<ServiceSpec>,
+}
+
+/// The manifest shipped if no `services.toml` is found — preserves the
+/// original Qdrant-only behavior.
+fn default_manifest() -> Manifest {
+  Manifest {
+    service: vec![ServiceSpec {
+      name: docker::QDRANT_CONTAINER_NAME.to_string(),
+      image: "qdrant/qdrant:latest".to_string(),
+      ports: vec![PortSpec { host: docker::QDRANT_DEFAULT_PORT, container: docker::QDRANT_DEFAULT_PORT }],
+      volumes: vec![format!("{}:/qdrant/storage", docker::QDRANT_VOLUME_NAME)],
+      env: HashMap::new(),
+      restart_policy: default_restart_policy(),
+      readiness: Some(ReadinessSpec { http_paths: vec!["/readyz".to_string(), "/healthz".to_string()] }),
+    }],
+  }
+}
+
+/// Load `services.toml` from the project root (dev) or the bundled resource
+/// dir (packaged — see `tauri.conf.json`'s `bundle.resources`), falling back
+/// to [`default_manifest`] if it's missing from both or fails to parse.
+pub fn load_manifest(app: &AppHandle, project_root: Option<&PathBuf>) -> Manifest {
+  let dev_path = project_root.map(|root| root.join("services.toml"));
+  let packaged_path = app.path().resolve("services.toml", BaseDirectory::Resource).ok();
+
+  let Some(path) = [dev_path, packaged_path].into_iter().flatten().find(|p| p.exists()) else {
+    log::info!("[services] No services.toml found (dev project root or packaged resources) — using the default Qdrant-only manifest");
+    return default_manifest();
+  };
+
+  match std::fs::read_to_string(&path).and_then(|s| {
+    toml::from_str::<Manifest>(&s).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+  }) {
+    Ok(manifest) if !manifest.service.is_empty() => manifest,
+    Ok(_) => {
+      log::warn!("[services] {path:?} has no [[service]] entries — using the default Qdrant-only manifest");
+      default_manifest()
+    }
+    Err(e) => {
+      log::warn!("[services] Could not parse {path:?}: {e} — using the default Qdrant-only manifest");
+      default_manifest()
+    }
+  }
+}
+
+fn primary_port(spec: &ServiceSpec) -> Option<u16> {
+  spec.ports.first().map(|p| p.host)
+}
+
+/// Where a brought-up service's published port is actually reachable —
+/// callers that need to build a URL (e.g. `QDRANT_URL`) need both the host
+/// and the port, since `services.toml` can remap the latter.
+#[derive(Debug, Clone)]
+pub struct ServiceEndpoint {
+  pub host: String,
+  pub port: Option<u16>,
+}
+
+/// Bring up every service in the manifest, in order, reusing an existing
+/// running/stopped container before creating a fresh one. Returns a map of
+/// service name → where its published port is reachable.
+pub fn bring_up(docker_host: Option<&str>, manifest: &Manifest, ss: &SharedStartupState) -> HashMap<String, ServiceEndpoint> {
+  let mut hosts = HashMap::new();
+
+  let DockerConnection { client: conn, engine_host } = match docker::connect(docker_host) {
+    Ok(c) => c,
+    Err(e) => {
+      ss.lock().unwrap()
+        .add_log(&format!("⚠ Could not connect to Docker Engine: {e} — ensure Docker is running"));
+      return hosts;
+    }
+  };
+
+  for spec in &manifest.service {
+    ss.lock().unwrap().set_phase(&format!("{}_starting", spec.name), &format!("Starting {}...", spec.name));
+
+    // If something is already serving this service's port, reuse it rather
+    // than fighting over it — but still gate on readiness below instead of
+    // trusting a bare TCP accept, which a not-yet-ready Qdrant will give.
+    let reusing = primary_port(spec).is_some_and(|port| TcpStream::connect((engine_host.as_str(), port)).is_ok());
+
+    if reusing {
+      ss.lock().unwrap()
+        .add_log(&format!("✓ {} already reachable on {engine_host} (reusing existing instance)", spec.name));
+    } else if let Err(e) = tauri::async_runtime::block_on(docker::ensure_service_running(&conn, spec, ss)) {
+      ss.lock().unwrap().add_log(&format!("⚠ Failed to bring up {}: {e} — ensure Docker is running", spec.name));
+      continue;
+    }
+
+    hosts.insert(spec.name.clone(), ServiceEndpoint { host: engine_host.clone(), port: primary_port(spec) });
+
+    let Some(port) = primary_port(spec) else { continue };
+
+    ss.lock().unwrap()
+      .set_phase(&format!("{}_wait", spec.name), &format!("Waiting for {} on {engine_host}:{port}...", spec.name));
+
+    let ready = docker::wait_for_container_healthy(docker_host, &spec.name, 20, 500, ss).unwrap_or_else(|| {
+      let paths: Vec<&str> = spec.readiness.iter().flat_map(|r| r.http_paths.iter()).map(String::as_str).collect();
+      if paths.is_empty() {
+        crate::wait_for_port(&engine_host, port, 20, 500)
+      } else {
+        crate::wait_for_health(&engine_host, port, &paths, 20, 500, ss, &spec.name)
+      }
+    });
+
+    if ready {
+      ss.lock().unwrap().add_log(&format!("✓ {} is ready", spec.name));
+    } else {
+      ss.lock().unwrap().add_log(&format!("⚠ {} not ready after 10s", spec.name));
+    }
+  }
+
+  hosts
+}
+
+/// Tear down every service in the manifest, in reverse order, so dependents
+/// stop before their dependencies.
+pub fn bring_down(docker_host: Option<&str>, manifest: &Manifest) {
+  let conn = match docker::connect(docker_host) {
+    Ok(c) => c,
+    Err(e) => {
+      log::warn!("[services] Could not connect to Docker Engine to bring services down: {e}");
+      return;
+    }
+  };
+
+  tauri::async_runtime::block_on(async {
+    for spec in manifest.service.iter().rev() {
+      docker::stop_service(&conn.client, &spec.name).await;
+    }
+  });
+}
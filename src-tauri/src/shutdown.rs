@@ -0,0 +1,103 @@
+// ── Graceful shutdown on OS signals ───────────────────────────────────────────
+//
+// `RunEvent::Exit`/`ExitRequested` only fire from inside the Tauri run loop,
+// so a SIGTERM from a service manager (or Ctrl-C when launched from a
+// terminal) would otherwise bypass it entirely and leave the backend process
+// and Docker-managed services running. We install an OS-level handler next
+// to the Tauri one and route both through the same teardown, guarded by
+// `Once` so it only ever runs a single time regardless of which path fires
+// first.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+
+use tauri::{AppHandle, Manager};
+
+use crate::{services, SharedStartupState};
+
+static TEARDOWN_DONE: Once = Once::new();
+static SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
+
+/// True from the moment a shutdown has been requested (signal or
+/// `RunEvent`), even before [`teardown`] has finished running. Anything that
+/// might resurrect the backend after shutdown started — the crash
+/// supervisor, in particular — must check this before relaunching it.
+pub(crate) fn is_shutting_down() -> bool {
+  SHUTTING_DOWN.load(Ordering::SeqCst)
+}
+
+/// Stop the backend child and bring every managed service down. Safe to call
+/// from both the signal handler and the Tauri `RunEvent` handler — only the
+/// first caller does any work.
+pub fn teardown(app: &AppHandle) {
+  SHUTTING_DOWN.store(true, Ordering::SeqCst);
+  TEARDOWN_DONE.call_once(|| {
+    log::info!("[shutdown] Tearing down backend and managed services");
+    crate::stop_backend(app);
+    let ss = app.state::<SharedStartupState>().inner().clone();
+    let docker_host = ss.lock().unwrap().docker_host_env();
+    let manifest = services::load_manifest(app, crate::find_project_root().as_ref());
+    services::bring_down(docker_host.as_deref(), &manifest);
+  });
+}
+
+#[cfg(unix)]
+static SIGNAL_RECEIVED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(unix)]
+pub fn install(app: AppHandle) {
+  use signal_hook::consts::{SIGHUP, SIGINT, SIGTERM};
+
+  for sig in [SIGTERM, SIGINT, SIGHUP] {
+    // SAFETY: the handler only does an atomic store — no allocation, no
+    // locking, nothing that isn't async-signal-safe. The actual teardown
+    // (which logs, locks a mutex, and makes blocking Docker API calls) runs
+    // on the plain background thread below instead of inside the handler.
+    let result = unsafe { signal_hook_registry::register(sig, || SIGNAL_RECEIVED.store(true, Ordering::SeqCst)) };
+    if let Err(e) = result {
+      log::warn!("[shutdown] Could not install handler for signal {sig}: {e}");
+    }
+  }
+
+  std::thread::spawn(move || {
+    loop {
+      if SIGNAL_RECEIVED.load(Ordering::SeqCst) {
+        teardown(&app);
+        return;
+      }
+      std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+  });
+}
+
+#[cfg(windows)]
+static WINDOWS_APP_HANDLE: std::sync::OnceLock<AppHandle> = std::sync::OnceLock::new();
+
+#[cfg(windows)]
+unsafe extern "system" fn console_ctrl_handler(ctrl_type: u32) -> windows_sys::Win32::Foundation::BOOL {
+  use windows_sys::Win32::System::Console::{CTRL_BREAK_EVENT, CTRL_C_EVENT, CTRL_CLOSE_EVENT, CTRL_SHUTDOWN_EVENT};
+
+  match ctrl_type {
+    CTRL_C_EVENT | CTRL_BREAK_EVENT | CTRL_CLOSE_EVENT | CTRL_SHUTDOWN_EVENT => {
+      if let Some(app) = WINDOWS_APP_HANDLE.get() {
+        teardown(app);
+      }
+      1 // TRUE — handled, don't let Windows terminate us before we're done.
+    }
+    _ => 0,
+  }
+}
+
+#[cfg(windows)]
+pub fn install(app: AppHandle) {
+  let _ = WINDOWS_APP_HANDLE.set(app);
+  // SAFETY: `console_ctrl_handler` is a valid extern "system" fn pointer
+  // matching `PHANDLER_ROUTINE`, registered for the lifetime of the process.
+  let ok = unsafe { windows_sys::Win32::System::Console::SetConsoleCtrlHandler(Some(console_ctrl_handler), 1) };
+  if ok == 0 {
+    log::warn!("[shutdown] Could not install console control handler");
+  }
+}
+
+#[cfg(not(any(unix, windows)))]
+pub fn install(_app: AppHandle) {}